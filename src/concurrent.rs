@@ -0,0 +1,122 @@
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A fixed-capacity LRU variant that trades the `LinkedHashMap`/`VecDeque`
+/// bookkeeping of [`crate::LRU`] for a pre-allocated `Vec` of slots stamped
+/// with a global generation counter.
+///
+/// Capacity is allocated up front and never grows, and `get` only needs
+/// `&self`: reads bump a shared `AtomicU64` and stamp the hit slot's own
+/// atomic, so the structure can sit behind an `Arc` and be read from many
+/// threads without a `Mutex` on the hot path. `insert` still needs `&mut
+/// self` to scan for a slot to reuse, since it is expected to be far rarer
+/// than reads in the read-heavy workloads this is meant for.
+pub struct ConcurrentLRU {
+    slots: Vec<(AtomicU64, Option<(String, Bytes)>)>,
+    index: HashMap<String, usize>,
+    generation: AtomicU64,
+}
+
+impl ConcurrentLRU {
+    /// Allocates a cache with room for exactly `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        let mut slots = Vec::with_capacity(capacity);
+        for _ in 0..capacity {
+            slots.push((AtomicU64::new(0), None));
+        }
+        ConcurrentLRU {
+            slots,
+            index: HashMap::with_capacity(capacity),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Looks up `key`, stamping its slot with a freshly bumped generation on
+    /// a hit. Only requires `&self`, so it is safe to call concurrently from
+    /// behind an `Arc`.
+    pub fn get(&self, key: &str) -> Option<Bytes> {
+        let &slot_index = self.index.get(key)?;
+        let (generation, entry) = &self.slots[slot_index];
+        let value = entry.as_ref().map(|(_, value)| value.clone())?;
+        let next_generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
+        generation.store(next_generation, Ordering::Relaxed);
+        Some(value)
+    }
+
+    /// Inserts `key`/`value`, reusing an empty slot if one is available and
+    /// otherwise overwriting whichever slot holds the smallest stored
+    /// generation (the least-recently-used slot). A no-op on a zero-capacity
+    /// cache, since there is no slot to hold the entry.
+    pub fn insert(&mut self, key: String, value: Bytes) {
+        if self.slots.is_empty() {
+            return;
+        }
+        if let Some(&slot_index) = self.index.get(&key) {
+            self.slots[slot_index].1 = Some((key, value));
+            let next_generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
+            self.slots[slot_index]
+                .0
+                .store(next_generation, Ordering::Relaxed);
+            return;
+        }
+
+        let slot_index = self.find_slot_for_insert();
+        if let Some((old_key, _)) = self.slots[slot_index].1.take() {
+            self.index.remove(&old_key);
+        }
+        let next_generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
+        self.slots[slot_index]
+            .0
+            .store(next_generation, Ordering::Relaxed);
+        self.slots[slot_index].1 = Some((key.clone(), value));
+        self.index.insert(key, slot_index);
+    }
+
+    fn find_slot_for_insert(&self) -> usize {
+        let mut lru_index = 0;
+        let mut lru_generation = u64::MAX;
+        for (i, (generation, entry)) in self.slots.iter().enumerate() {
+            if entry.is_none() {
+                return i;
+            }
+            let generation = generation.load(Ordering::Relaxed);
+            if generation < lru_generation {
+                lru_generation = generation;
+                lru_index = i;
+            }
+        }
+        lru_index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserts_and_reads_back() {
+        let mut cache = ConcurrentLRU::new(2);
+        cache.insert("a".to_owned(), Bytes::from("a"));
+        assert_eq!(cache.get("a"), Some(Bytes::from("a")));
+        assert_eq!(cache.get("missing"), None);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_slot() {
+        let mut cache = ConcurrentLRU::new(2);
+        cache.insert("a".to_owned(), Bytes::from("a"));
+        cache.insert("b".to_owned(), Bytes::from("b"));
+        // Touch "a" so "b" becomes the least-recently-used slot.
+        cache.get("a");
+        cache.insert("c".to_owned(), Bytes::from("c"));
+
+        assert_eq!(cache.get("a"), Some(Bytes::from("a")));
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("c"), Some(Bytes::from("c")));
+    }
+}
@@ -1,33 +1,151 @@
 use bytes::Bytes;
 use linked_hash_map::LinkedHashMap;
+use std::borrow::Borrow;
 use std::collections::VecDeque;
+use std::hash::Hash;
 
-pub struct LRU {
-    items: LinkedHashMap<String, Bytes>,
+mod concurrent;
+pub use concurrent::ConcurrentLRU;
+
+/// Default cost an entry is charged against `max_size` when no `cost_fn` is
+/// configured via [`LRU::cost_fn`]. Implemented for `Bytes` so the byte-keyed,
+/// byte-valued [`ByteCache`] alias keeps its original behavior.
+pub trait Weight {
+    fn weight(&self) -> usize;
+}
+
+impl Weight for Bytes {
+    fn weight(&self) -> usize {
+        self.len()
+    }
+}
+
+/// A user-supplied function computing an entry's weight against `max_size`,
+/// overriding the default supplied by [`Weight`].
+type CostFn<K, V> = Box<dyn Fn(&K, &V) -> usize>;
+
+/// Selects the eviction strategy used by [`LRU`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Policy {
+    /// Plain least-recently-used eviction (the original behavior).
+    Lru,
+    /// Adaptive Replacement Cache. Balances recency against frequency by
+    /// splitting entries into a recency list (T1) and a frequency list (T2),
+    /// and remembering the keys of recently evicted entries in ghost lists
+    /// (B1/B2) to decide which list to favor over time.
+    Arc,
+}
+
+/// The original key/value shape: string keys, byte-string values.
+pub type ByteCache = LRU<String, Bytes>;
+
+/// A snapshot of a cache's hit-rate and access accounting, returned by
+/// [`LRU::stats`].
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct CacheStats {
+    pub accesses: usize,
+    pub hits: usize,
+    pub current_size: usize,
+    pub entry_count: usize,
+    pub evictions: usize,
+    /// Misses on a key that was cached recently enough to still be in
+    /// `history` (see [`LRU::has_evicted_recently`]): a sign capacity is too
+    /// small rather than the workload just being cold.
+    pub ghost_hits: usize,
+}
+
+impl CacheStats {
+    pub fn hit_ratio(&self) -> f64 {
+        if self.accesses == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.accesses as f64
+        }
+    }
+}
+
+pub struct LRU<K, V> {
+    // Under `Policy::Lru` this is the only list. Under `Policy::Arc` it plays
+    // the role of T1: entries seen exactly once recently. Each entry carries
+    // its value alongside the cost it was charged on insertion, so eviction
+    // can subtract that exact amount instead of recomputing it.
+    items: LinkedHashMap<K, (V, usize)>,
+    // T2: entries seen at least twice recently. Only populated under `Policy::Arc`.
+    t2: LinkedHashMap<K, (V, usize)>,
+    // Ghost lists: keys only (no values) of entries evicted from T1/T2, used
+    // to adapt `p` toward recency or frequency. Only populated under `Policy::Arc`.
+    ghost_b1: VecDeque<K>,
+    ghost_b2: VecDeque<K>,
+    // Target size for T1 under ARC; grows toward recency on a B1 hit and
+    // shrinks toward frequency on a B2 hit.
+    p: usize,
+    policy: Policy,
+    // Weighs an entry for eviction purposes; `value.weight()` when unset.
+    cost_fn: Option<CostFn<K, V>>,
     max_size: usize,
     current_size: usize,
-    history: VecDeque<String>,
+    history: VecDeque<K>,
     accesses: usize,
     hits: usize,
+    evictions: usize,
+    // Misses on a key `has_evicted_recently` reports true for: the cache was
+    // big enough to have kept this around, capacity was just too small.
+    ghost_hits: usize,
+    // `max_size` is a hard ceiling; `cache_target` is the softer fill level
+    // the cache actually tries to stay under, recomputed from load every
+    // `recompute_every` inserts.
+    min_capacity_limit: usize,
+    max_capacity_limit: usize,
+    min_cache_percent: f64,
+    max_cache_percent: f64,
+    cache_target: usize,
+    evict_batch: usize,
+    recompute_every: usize,
+    inserts_since_recompute: usize,
+    // Set by any of the adaptive-target builders. Until then, the adaptive
+    // pass is a true no-op: callers who never touch this feature keep the
+    // plain `max_size` ceiling with no extra enforcement pass.
+    adaptive_configured: bool,
 }
 
-impl LRU {
+impl<K, V> LRU<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Weight,
+{
     pub fn new() -> Self {
         LRU {
             items: LinkedHashMap::new(),
+            t2: LinkedHashMap::new(),
+            ghost_b1: VecDeque::new(),
+            ghost_b2: VecDeque::new(),
+            p: 0,
+            policy: Policy::Lru,
+            cost_fn: None,
             max_size: 64,
             accesses: 0,
             hits: 0,
+            evictions: 0,
+            ghost_hits: 0,
             current_size: 0,
             history: VecDeque::with_capacity(1000),
+            min_capacity_limit: 0,
+            max_capacity_limit: usize::MAX,
+            min_cache_percent: 1.0,
+            max_cache_percent: 1.0,
+            cache_target: 64,
+            evict_batch: 1,
+            recompute_every: 1,
+            inserts_since_recompute: 0,
+            adaptive_configured: false,
         }
     }
     ///
     /// Builder for max_size, only outside-configurable value for cache
     ///
     /// ```
-    /// use lfu_vecs::LFU;
-    /// let lfu = LFU::new().max_size(1024);
+    /// use lru_with_history::ByteCache;
+    /// let lru = ByteCache::new().max_size(1024);
     /// ```
     ///
     pub fn max_size(mut self, size: usize) -> Self {
@@ -35,42 +153,357 @@ impl LRU {
         self
     }
 
-    pub fn current_size(self) -> usize {
+    /// Builder to select the eviction policy. Defaults to `Policy::Lru`.
+    pub fn policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Builder supplying a custom cost function used to weigh entries against
+    /// `max_size` instead of the default `V::weight()`. Lets callers turn
+    /// this into a byte-bounded cache, an item-count-bounded cache
+    /// (`|_, _| 1`), or anything in between (e.g. weighting by decompressed
+    /// size).
+    ///
+    /// ```
+    /// use lru_with_history::ByteCache;
+    /// // Cap by number of entries rather than bytes.
+    /// let lru = ByteCache::new().max_size(100).cost_fn(|_key, _value| 1);
+    /// ```
+    pub fn cost_fn<F>(mut self, cost_fn: F) -> Self
+    where
+        F: Fn(&K, &V) -> usize + 'static,
+    {
+        self.cost_fn = Some(Box::new(cost_fn));
+        self
+    }
+
+    /// Lower bound of the `current_size` range the adaptive fill target
+    /// interpolates over. Below this, the cache targets 100% of `max_size`.
+    pub fn min_capacity_limit(mut self, limit: usize) -> Self {
+        self.min_capacity_limit = limit;
+        self.adaptive_configured = true;
+        self
+    }
+
+    /// Upper bound of the `current_size` range the adaptive fill target
+    /// interpolates over. At or above this, the cache targets `min_cache_percent`.
+    pub fn max_capacity_limit(mut self, limit: usize) -> Self {
+        self.max_capacity_limit = limit;
+        self.adaptive_configured = true;
+        self
+    }
+
+    /// Fill percentage (0.0-1.0) of `max_size` the cache targets once
+    /// `current_size` reaches `max_capacity_limit`, i.e. under memory pressure.
+    pub fn min_cache_percent(mut self, percent: f64) -> Self {
+        self.min_cache_percent = percent;
+        self.adaptive_configured = true;
+        self
+    }
+
+    /// Fill percentage (0.0-1.0) of `max_size` the cache targets at
+    /// `min_capacity_limit`, i.e. when memory is abundant.
+    pub fn max_cache_percent(mut self, percent: f64) -> Self {
+        self.max_cache_percent = percent;
+        self.adaptive_configured = true;
+        self
+    }
+
+    /// How many LRU entries to evict at once when `current_size` exceeds
+    /// `cache_target`, instead of evicting a single entry at a time.
+    pub fn evict_batch(mut self, batch: usize) -> Self {
+        self.evict_batch = batch;
+        self.adaptive_configured = true;
+        self
+    }
+
+    /// How many inserts to wait between recomputing `cache_target`.
+    pub fn recompute_every(mut self, inserts: usize) -> Self {
+        self.recompute_every = inserts.max(1);
+        self.adaptive_configured = true;
+        self
+    }
+
+    /// Linearly interpolates the target fill percentage between
+    /// `max_cache_percent` (at `min_capacity_limit`) and `min_cache_percent`
+    /// (at `max_capacity_limit`), using 100% below `min_capacity_limit` and
+    /// `min_cache_percent` at or above `max_capacity_limit`, and stores the
+    /// resulting byte/item target in `cache_target`.
+    fn recompute_cache_target(&mut self) {
+        let percent = if self.current_size < self.min_capacity_limit {
+            1.0
+        } else if self.current_size >= self.max_capacity_limit {
+            self.min_cache_percent
+        } else {
+            let range = (self.max_capacity_limit - self.min_capacity_limit) as f64;
+            let progress = (self.current_size - self.min_capacity_limit) as f64 / range;
+            self.max_cache_percent - progress * (self.max_cache_percent - self.min_cache_percent)
+        };
+        self.cache_target = ((self.max_size as f64) * percent) as usize;
+    }
+
+    fn cost_of(&self, key: &K, value: &V) -> usize {
+        match &self.cost_fn {
+            Some(cost_fn) => cost_fn(key, value),
+            None => value.weight(),
+        }
+    }
+
+    pub fn current_size(&self) -> usize {
         self.current_size
     }
 
-    pub fn has_evicted_recently(&self, key: &str) -> bool {
+    /// A snapshot of hit-rate and access accounting, useful for deciding
+    /// whether the cache is sized correctly.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            accesses: self.accesses,
+            hits: self.hits,
+            current_size: self.current_size,
+            entry_count: self.items.len() + self.t2.len(),
+            evictions: self.evictions,
+            ghost_hits: self.ghost_hits,
+        }
+    }
+
+    /// Zeroes out `accesses`, `hits`, `evictions` and `ghost_hits` without
+    /// touching any cached entries.
+    pub fn reset_stats(&mut self) {
+        self.accesses = 0;
+        self.hits = 0;
+        self.evictions = 0;
+        self.ghost_hits = 0;
+    }
+
+    /// Updates `max_size` in place. If the new limit is smaller than the
+    /// current size, immediately evicts LRU entries until the cache fits
+    /// again, recording each evicted key in `history` exactly as `insert`
+    /// does. Lets callers retune a memory budget without rebuilding the cache.
+    pub fn set_max_size(&mut self, new_size: usize) {
+        self.max_size = new_size;
+        self.evict_until(self.max_size, usize::MAX);
+    }
+
+    pub fn has_evicted_recently<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
         self.history
             .iter()
-            .any(|historical_key| historical_key.eq(key))
+            .any(|historical_key| historical_key.borrow() == key)
     }
 
-    pub fn contains_key(&self, key: &str) -> bool {
-        self.items.contains_key(key)
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.items.contains_key(key) || self.t2.contains_key(key)
     }
 
-    pub fn insert(&mut self, key: String, value: Bytes) -> Option<Bytes> {
-        // TODO: implement eviction code
-        while self.current_size + value.len() > self.max_size {
-            if let Some((popped_key, popped_item)) = self.items.pop_front() {
-                self.current_size -= popped_item.len();
-                // register deletion in history
-                if self.history.len() > self.max_size {
-                    self.history.pop_back();
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let cost = self.cost_of(&key, &value);
+        let old_value = match self.policy {
+            Policy::Lru => self.insert_lru(key, value, cost),
+            Policy::Arc => self.insert_arc(key, value, cost),
+        };
+
+        // The adaptive target only exists once a caller opts in via one of
+        // the adaptive builders; otherwise `max_size` (already enforced by
+        // `insert_lru`/`insert_arc`) is the only ceiling. This also keeps the
+        // trim itself tied to the recompute tick instead of re-applying a
+        // stale `cache_target` on every insert in between.
+        if self.adaptive_configured {
+            self.inserts_since_recompute += 1;
+            if self.inserts_since_recompute >= self.recompute_every {
+                self.inserts_since_recompute = 0;
+                self.recompute_cache_target();
+                if self.current_size > self.cache_target {
+                    self.evict_until(self.cache_target, self.evict_batch);
                 }
-                self.history.push_front(popped_key);
             }
         }
+
+        old_value
+    }
+
+    fn insert_lru(&mut self, key: K, value: V, cost: usize) -> Option<V> {
+        self.evict_until(self.max_size.saturating_sub(cost), usize::MAX);
         // add and increment
-        self.current_size += value.len();
-        self.items.insert(key, value)
+        self.current_size += cost;
+        self.items
+            .insert(key, (value, cost))
+            .map(|(old_value, _)| old_value)
     }
-    pub fn get(&mut self, key: &str) -> Option<&Bytes> {
+
+    fn insert_arc(&mut self, key: K, value: V, cost: usize) -> Option<V> {
+        // Hit in T1: promote to the MRU end of T2.
+        if let Some((old_value, old_cost)) = self.items.remove(&key) {
+            self.current_size = self.current_size + cost - old_cost;
+            self.t2.insert(key, (value, cost));
+            return Some(old_value);
+        }
+        // Hit in T2: refresh at the MRU end.
+        if let Some((old_value, old_cost)) = self.t2.remove(&key) {
+            self.current_size = self.current_size + cost - old_cost;
+            self.t2.insert(key, (value, cost));
+            return Some(old_value);
+        }
+
+        // Hit in a ghost list: adapt `p` and the entry graduates straight into T2.
+        // `p` is compared against `items.len()` (an entry count), so it must
+        // be capped in that same unit rather than against `max_size` (a cost
+        // budget that can be in bytes or anything else `cost_fn` returns).
+        let entry_count = self.items.len() + self.t2.len();
+        let mut from_ghost = false;
+        if let Some(pos) = self.ghost_b1.iter().position(|k| k == &key) {
+            self.ghost_b1.remove(pos);
+            let delta = std::cmp::max(1, self.ghost_b2.len() / self.ghost_b1.len().max(1));
+            self.p = std::cmp::min(entry_count, self.p + delta);
+            from_ghost = true;
+        } else if let Some(pos) = self.ghost_b2.iter().position(|k| k == &key) {
+            self.ghost_b2.remove(pos);
+            let delta = std::cmp::max(1, self.ghost_b1.len() / self.ghost_b2.len().max(1));
+            self.p = self.p.saturating_sub(delta);
+            from_ghost = true;
+        }
+
+        self.evict_until(self.max_size.saturating_sub(cost), usize::MAX);
+        self.current_size += cost;
+        if from_ghost {
+            self.t2.insert(key, (value, cost));
+        } else {
+            self.items.insert(key, (value, cost));
+        }
+        None
+    }
+
+    /// Evicts entries until `current_size` is at or under `target`, stopping
+    /// early after `max_evictions` entries (pass `usize::MAX` for no cap).
+    /// Used both for the hard `max_size` ceiling and for batched trims down
+    /// to the softer `cache_target`.
+    fn evict_until(&mut self, target: usize, max_evictions: usize) -> usize {
+        match self.policy {
+            Policy::Lru => self.evict_lru_until(target, max_evictions),
+            Policy::Arc => self.evict_arc_until(target, max_evictions),
+        }
+    }
+
+    fn evict_lru_until(&mut self, target: usize, max_evictions: usize) -> usize {
+        let mut evicted = 0;
+        while self.current_size > target && evicted < max_evictions {
+            match self.items.pop_front() {
+                Some((popped_key, (_, popped_cost))) => {
+                    self.current_size -= popped_cost;
+                    self.remember_eviction(popped_key);
+                    evicted += 1;
+                }
+                None => break,
+            }
+        }
+        evicted
+    }
+
+    /// Evicts from T1 or T2 depending on how T1's size compares to the
+    /// target `p`, recording the evicted key in the corresponding ghost list
+    /// (capped at the live entry count). Falls back to the other list when
+    /// the chosen one is empty, so the `max_size` ceiling is always honored
+    /// as long as either list has something to give up.
+    fn evict_arc_until(&mut self, target: usize, max_evictions: usize) -> usize {
+        let mut evicted = 0;
+        while self.current_size > target && evicted < max_evictions {
+            // Ghost lists track evicted keys, not bytes, so cap their length
+            // against the live entry count rather than the byte-oriented
+            // `max_size`.
+            let entry_count = self.items.len() + self.t2.len();
+            let from_t1 = self.items.len() > self.p;
+            let popped = if from_t1 {
+                self.items.pop_front().map(|entry| (true, entry))
+            } else {
+                self.t2.pop_front().map(|entry| (false, entry))
+            };
+            // Preferred list was empty; try the other one before giving up.
+            let popped = popped.or_else(|| {
+                if from_t1 {
+                    self.t2.pop_front().map(|entry| (false, entry))
+                } else {
+                    self.items.pop_front().map(|entry| (true, entry))
+                }
+            });
+
+            match popped {
+                Some((true, (k, (_, popped_cost)))) => {
+                    self.current_size -= popped_cost;
+                    self.remember_eviction(k.clone());
+                    self.ghost_b1.push_front(k);
+                    if self.ghost_b1.len() > entry_count {
+                        self.ghost_b1.pop_back();
+                    }
+                    evicted += 1;
+                }
+                Some((false, (k, (_, popped_cost)))) => {
+                    self.current_size -= popped_cost;
+                    self.remember_eviction(k.clone());
+                    self.ghost_b2.push_front(k);
+                    if self.ghost_b2.len() > entry_count {
+                        self.ghost_b2.pop_back();
+                    }
+                    evicted += 1;
+                }
+                None => break,
+            }
+        }
+        evicted
+    }
+
+    fn remember_eviction(&mut self, key: K) {
+        self.evictions += 1;
+        if self.history.len() > self.max_size {
+            self.history.pop_back();
+        }
+        self.history.push_front(key);
+    }
+
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq + ToOwned<Owned = K>,
+    {
         self.accesses += 1;
-        if self.items.contains_key(key) {
-            self.hits += 1;
+        match self.policy {
+            Policy::Lru => {
+                if self.items.contains_key(key) {
+                    self.hits += 1;
+                } else if self.has_evicted_recently(key) {
+                    self.ghost_hits += 1;
+                }
+                self.items.get(key).map(|(value, _)| value)
+            }
+            Policy::Arc => {
+                if self.items.contains_key(key) {
+                    self.hits += 1;
+                    // Promote from T1 to the MRU end of T2.
+                    let owned_key = key.to_owned();
+                    let entry = self.items.remove(key).unwrap();
+                    self.t2.insert(owned_key, entry);
+                    return self.t2.get(key).map(|(value, _)| value);
+                }
+                if self.t2.contains_key(key) {
+                    self.hits += 1;
+                    // Refresh at the MRU end of T2.
+                    let owned_key = key.to_owned();
+                    let entry = self.t2.remove(key).unwrap();
+                    self.t2.insert(owned_key, entry);
+                    return self.t2.get(key).map(|(value, _)| value);
+                }
+                if self.has_evicted_recently(key) {
+                    self.ghost_hits += 1;
+                }
+                None
+            }
         }
-        self.items.get(key)
     }
 }
 
@@ -81,7 +514,7 @@ mod tests {
 
     #[test]
     fn no_evictions() {
-        let mut lru = LRU::new();
+        let mut lru = ByteCache::new();
         lru = lru.max_size(128);
         lru.insert("a".to_owned(), Bytes::from("a"));
         assert_eq!(lru.get("a"), Some(&Bytes::from("a")));
@@ -89,7 +522,7 @@ mod tests {
 
     #[test]
     fn test_evictions_history() {
-        let mut lru = LRU::new();
+        let mut lru = ByteCache::new();
         lru = lru.max_size(5); // smaller by one from what we will ask for
         lru.insert("a".to_owned(), Bytes::from("abc"));
         lru.insert("b".to_owned(), Bytes::from("dfg"));
@@ -97,4 +530,112 @@ mod tests {
         assert_eq!(lru.history, vec!["a"]);
         assert_eq!(lru.has_evicted_recently("a"), true);
     }
+
+    #[test]
+    fn arc_promotes_t1_hit_to_t2() {
+        let mut lru = ByteCache::new().max_size(128).policy(Policy::Arc);
+        lru.insert("a".to_owned(), Bytes::from("a"));
+        assert_eq!(lru.get("a"), Some(&Bytes::from("a")));
+        assert!(lru.t2.contains_key("a"));
+        assert!(!lru.items.contains_key("a"));
+    }
+
+    #[test]
+    fn arc_ghost_hit_adapts_p_and_lands_in_t2() {
+        let mut lru = ByteCache::new().max_size(1).policy(Policy::Arc);
+        lru.insert("a".to_owned(), Bytes::from("a"));
+        lru.insert("b".to_owned(), Bytes::from("b")); // evicts "a" into ghost_b1
+        assert!(lru.ghost_b1.contains(&"a".to_owned()));
+
+        lru.insert("a".to_owned(), Bytes::from("a")); // ghost hit
+        assert_eq!(lru.p, 1);
+        assert!(lru.t2.contains_key("a"));
+        assert!(!lru.ghost_b1.contains(&"a".to_owned()));
+    }
+
+    #[test]
+    fn set_max_size_shrinks_and_evicts_immediately() {
+        let mut lru = ByteCache::new().max_size(10);
+        lru.insert("a".to_owned(), Bytes::from("abc"));
+        lru.insert("b".to_owned(), Bytes::from("def"));
+
+        lru.set_max_size(3);
+
+        assert_eq!(lru.current_size(), 3);
+        assert_eq!(lru.get("a"), None);
+        assert_eq!(lru.has_evicted_recently("a"), true);
+        assert_eq!(lru.get("b"), Some(&Bytes::from("def")));
+    }
+
+    #[test]
+    fn custom_cost_fn_bounds_by_entry_count() {
+        let mut lru = ByteCache::new().max_size(2).cost_fn(|_key, _value| 1);
+        lru.insert("a".to_owned(), Bytes::from("aaaaaaaaaa"));
+        lru.insert("b".to_owned(), Bytes::from("b"));
+        lru.insert("c".to_owned(), Bytes::from("c"));
+
+        assert_eq!(lru.get("a"), None);
+        assert_eq!(lru.get("b"), Some(&Bytes::from("b")));
+        assert_eq!(lru.get("c"), Some(&Bytes::from("c")));
+    }
+
+    #[test]
+    fn adaptive_target_evicts_in_capped_batches() {
+        let mut lru = ByteCache::new()
+            .max_size(20)
+            .cost_fn(|_key, _value| 1)
+            .min_capacity_limit(0)
+            .max_capacity_limit(20)
+            .min_cache_percent(0.1)
+            .max_cache_percent(1.0)
+            .evict_batch(3)
+            .recompute_every(5);
+
+        for i in 0..15 {
+            lru.insert(format!("k{i}"), Bytes::from("x"));
+        }
+        // On the 15th insert the target recomputes down to 6, but a single
+        // call only evicts up to `evict_batch` (3) entries, so current_size
+        // only drops from 15 to 12 instead of all the way to the target.
+        assert_eq!(lru.current_size(), 12);
+        assert_eq!(lru.get("k0"), None);
+        assert_eq!(lru.get("k1"), None);
+        assert_eq!(lru.get("k2"), None);
+        assert!(lru.get("k3").is_some());
+    }
+
+    #[test]
+    fn generic_over_non_string_keys() {
+        let mut lru: LRU<u64, Bytes> = LRU::new().max_size(128);
+        lru.insert(1, Bytes::from("one"));
+        assert_eq!(lru.get(&1), Some(&Bytes::from("one")));
+        assert_eq!(lru.get(&2), None);
+    }
+
+    #[test]
+    fn stats_track_hits_evictions_and_ghost_hits() {
+        let mut lru = ByteCache::new().max_size(5);
+        lru.insert("a".to_owned(), Bytes::from("abc"));
+        lru.insert("b".to_owned(), Bytes::from("dfg")); // evicts "a"
+
+        lru.get("b"); // hit
+        lru.get("a"); // ghost hit: "a" was evicted, not just never seen
+        lru.get("z"); // cold miss: never cached, no ghost hit
+
+        let stats = lru.stats();
+        assert_eq!(stats.accesses, 3);
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.evictions, 1);
+        assert_eq!(stats.ghost_hits, 1);
+        assert_eq!(stats.current_size, 3);
+        assert_eq!(stats.entry_count, 1);
+        assert_eq!(stats.hit_ratio(), 1.0 / 3.0);
+
+        lru.reset_stats();
+        let stats = lru.stats();
+        assert_eq!(stats.accesses, 0);
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.evictions, 0);
+        assert_eq!(stats.ghost_hits, 0);
+    }
 }